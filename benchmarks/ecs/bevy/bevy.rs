@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use rayon::prelude::*;
 use std::time::Instant;
 
 #[derive(Debug)]
@@ -13,8 +14,52 @@ struct BenchmarkMetrics {
     percent60: f32,
     percent30: f32,
     jank_score: u32,
+    rollouts_per_sec: f32,
+    archetype_moves: u32,
 }
 
+// Controls how the hot queries are chunked when fanned out across a rayon
+// thread pool. Which mode is active is derived entirely from which system
+// variants `build_app` registers (see below), not from a flag on this
+// resource.
+#[derive(Resource, Clone, Copy)]
+struct ParallelConfig {
+    chunk_size: usize,
+}
+
+impl Default for ParallelConfig {
+    fn default() -> Self {
+        Self { chunk_size: 256 }
+    }
+}
+
+// Controls the Monte Carlo target-selection AI: how many rollouts each
+// candidate enemy gets and how many combat rounds each rollout simulates.
+#[derive(Resource, Clone, Copy)]
+struct MctsConfig {
+    rollouts_per_candidate: u32,
+    rollout_depth: u32,
+}
+
+impl Default for MctsConfig {
+    fn default() -> Self {
+        Self {
+            rollouts_per_candidate: 8,
+            rollout_depth: 3,
+        }
+    }
+}
+
+// Total rollouts run across the whole simulation, used to derive the
+// `rollouts_per_sec` benchmark metric.
+#[derive(Resource, Default)]
+struct RolloutCounter(u64);
+
+// Total level-tier marker swaps (archetype moves) performed by
+// `leveling_system`, surfaced as the `archetype_moves` benchmark metric.
+#[derive(Resource, Default)]
+struct ArchetypeMoveCounter(u32);
+
 // Define components matching Murow's benchmark
 #[derive(Component)]
 struct Transform2D {
@@ -72,33 +117,100 @@ struct Lifetime {
     remaining: f32,
 }
 
+// Damage dealt this frame accumulates here instead of being applied (and
+// collected into a scratch `Vec`) inline, so attackers targeting the same
+// entity never race and combat becomes order-independent. `last_attacker`
+// lets `death_system` credit a kill's XP without a separate lookup.
+#[derive(Component, Default)]
+struct DamageAccumulator {
+    pending: f32,
+    last_attacker: Option<Entity>,
+}
+
+// A current/max-backed resource pool, mirroring the roguelike tutorial's
+// `Pools` design.
+#[derive(Clone, Copy)]
+struct Pool {
+    current: i32,
+    max: i32,
+}
+
+#[derive(Component)]
+struct Pools {
+    hit_points: Pool,
+    mana: Pool,
+    xp: i32,
+    level: u8,
+}
+
+#[derive(Component)]
+struct Skills {
+    melee: u8,
+    defense: u8,
+    magic: u8,
+}
+
+// Level-tier markers. `leveling_system` removes the old one and inserts the
+// next when an entity crosses a level threshold, which moves the entity to
+// a different archetype mid-simulation -- the structural churn real RPGs
+// produce as characters grow, which a fixed component layout never exercises.
+#[derive(Component)]
+struct Novice;
+
+#[derive(Component)]
+struct Veteran;
+
+#[derive(Component)]
+struct Elite;
+
 #[derive(Resource)]
 struct FrameCounter(u32);
 
 #[derive(Resource)]
 struct DeltaTime(f32);
 
-// Simple random number generator for deterministic benchmarking
-struct SimpleRng {
-    seed: u32,
+// Deterministic XorShift128 RNG, mirroring the `SeedableRng`/`XorShiftRng`
+// approach used by the Entelect strategy code. Replaces the old LCG, whose
+// low bits were weak enough to show up as patterns in spawn/AI randomness.
+struct XorShiftRng {
+    x: u32,
+    y: u32,
+    z: u32,
+    w: u32,
 }
 
-impl SimpleRng {
+impl XorShiftRng {
     fn new(seed: u32) -> Self {
-        Self { seed }
+        // Splat the scalar seed across all four lanes with distinct
+        // constants so no seed (zero included) can leave all four lanes at
+        // zero, the one state XorShift128 never leaves.
+        Self {
+            x: seed ^ 0x9E37_79B9,
+            y: seed ^ 0x243F_6A88,
+            z: seed ^ 0x85A3_08D3,
+            w: seed ^ 0x1319_8A2E,
+        }
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        let t = self.x ^ (self.x << 11);
+        self.x = self.y;
+        self.y = self.z;
+        self.z = self.w;
+        self.w = self.w ^ (self.w >> 19) ^ t ^ (t >> 8);
+        self.w
     }
 
     fn next_f32(&mut self) -> f32 {
-        self.seed = self.seed.wrapping_mul(1103515245).wrapping_add(12345);
-        ((self.seed / 65536) % 32768) as f32 / 32768.0
+        (self.next_u32() >> 8) as f32 / (1u32 << 24) as f32
     }
 
     fn next_u16(&mut self) -> u16 {
-        (self.next_f32() * 65535.0) as u16
+        (self.next_u32() >> 16) as u16
     }
 
     fn next_u8(&mut self) -> u8 {
-        (self.next_f32() * 255.0) as u8
+        (self.next_u32() >> 24) as u8
     }
 }
 
@@ -135,7 +247,11 @@ fn boundary_system(mut query: Query<&mut Transform2D>) {
     }
 }
 
-fn health_regen_system(mut query: Query<&mut Health>, frame: Res<FrameCounter>) {
+fn health_regen_system(
+    mut query: Query<&mut Health>,
+    mut pools_query: Query<&mut Pools>,
+    frame: Res<FrameCounter>,
+) {
     if frame.0 % 30 == 0 {
         for mut h in query.iter_mut() {
             if h.current > 0 && h.current < h.max {
@@ -143,6 +259,12 @@ fn health_regen_system(mut query: Query<&mut Health>, frame: Res<FrameCounter>)
                 h.current = if new_health > h.max { h.max } else { new_health };
             }
         }
+
+        // Mana regenerates the same tick health does, so `ai_mcts_system`
+        // has a pool to spend on its next search instead of being spent dry.
+        for mut pools in pools_query.iter_mut() {
+            pools.mana.current = (pools.mana.current + 5).min(pools.mana.max);
+        }
     }
 }
 
@@ -155,25 +277,43 @@ fn cooldown_system(mut query: Query<&mut Cooldown>, delta_time: Res<DeltaTime>)
     }
 }
 
-fn combat_system(
+// Shared by `combat_system_legacy` and `combat_write_damage_system` so
+// `run_combat_comparison`'s avg/p99 delta isolates the accumulator
+// redesign's cost instead of also conflating it with skill arithmetic that
+// only one of the two paths applied.
+fn compute_melee_damage(base_damage: u16, melee: u8, armor: u16, defense: u8) -> f32 {
+    let mut damage_dealt = base_damage as f32 * (1.0 + melee as f32 * 0.05);
+
+    let reduced = damage_dealt - armor as f32 * 0.1;
+    damage_dealt = if reduced < 1.0 { 1.0 } else { reduced };
+
+    let reduced = damage_dealt - defense as f32 * 0.1;
+    if reduced < 1.0 { 1.0 } else { reduced }
+}
+
+// Kept only as the baseline for `run_combat_comparison`'s avg/p99 delta
+// against the accumulator-based `combat_write_damage_system` /
+// `apply_damage_system` pair below; the live benchmark no longer schedules
+// this one.
+fn combat_system_legacy(
     mut health_query: Query<&mut Health>,
-    mut attacker_query: Query<(&mut Cooldown, &Damage, &Target)>,
+    mut attacker_query: Query<(&mut Cooldown, &Damage, &Target, &Skills)>,
     armor_query: Query<&Armor>,
+    skills_query: Query<&Skills>,
     frame: Res<FrameCounter>,
 ) {
     if frame.0 % 5 == 0 {
         // Collect all updates first to avoid borrow checker issues
         let mut updates: Vec<(Entity, u16, f32)> = Vec::new();
 
-        for (cd, dmg, target) in attacker_query.iter() {
+        for (cd, dmg, target, attacker_skills) in attacker_query.iter() {
             if cd.current == 0.0 {
                 if let Ok(target_health) = health_query.get(target.entity_id) {
-                    let mut damage_dealt = dmg.amount;
-
-                    if let Ok(armor) = armor_query.get(target.entity_id) {
-                        let reduced = dmg.amount as f32 - armor.value as f32 * 0.1;
-                        damage_dealt = if reduced < 1.0 { 1 } else { reduced as u16 };
-                    }
+                    let armor = armor_query.get(target.entity_id).map_or(0, |a| a.value);
+                    let defense = skills_query.get(target.entity_id).map_or(0, |s| s.defense);
+                    let damage_dealt =
+                        compute_melee_damage(dmg.amount, attacker_skills.melee, armor, defense)
+                            as u16;
 
                     let new_health = if target_health.current > damage_dealt {
                         target_health.current - damage_dealt
@@ -194,7 +334,7 @@ fn combat_system(
         }
 
         // Reset cooldowns separately
-        for (mut cd, _, _) in attacker_query.iter_mut() {
+        for (mut cd, _, _, _) in attacker_query.iter_mut() {
             if cd.current == 0.0 {
                 cd.current = cd.max;
             }
@@ -202,14 +342,116 @@ fn combat_system(
     }
 }
 
-fn death_system(mut commands: Commands, query: Query<(Entity, &Health)>) {
-    for (entity, h) in query.iter() {
+// Write phase: attackers push armor-reduced damage into their target's
+// `DamageAccumulator` instead of touching `Health` directly, so this system
+// never conflicts with anything reading `Health`.
+fn combat_write_damage_system(
+    mut attacker_query: Query<(Entity, &mut Cooldown, &Damage, &Target, &Skills)>,
+    armor_query: Query<&Armor>,
+    skills_query: Query<&Skills>,
+    mut accumulator_query: Query<&mut DamageAccumulator>,
+    frame: Res<FrameCounter>,
+) {
+    if frame.0 % 5 == 0 {
+        for (attacker, mut cd, dmg, target, attacker_skills) in attacker_query.iter_mut() {
+            if cd.current == 0.0 {
+                let armor = armor_query.get(target.entity_id).map_or(0, |a| a.value);
+                let defense = skills_query.get(target.entity_id).map_or(0, |s| s.defense);
+                let damage_dealt =
+                    compute_melee_damage(dmg.amount, attacker_skills.melee, armor, defense);
+
+                if let Ok(mut accumulator) = accumulator_query.get_mut(target.entity_id) {
+                    accumulator.pending += damage_dealt;
+                    accumulator.last_attacker = Some(attacker);
+                }
+
+                cd.current = cd.max;
+            }
+        }
+    }
+}
+
+// Apply phase: subtract each entity's accumulated damage from its `Health`,
+// clamp at zero, then clear the accumulator for the next frame.
+fn apply_damage_system(mut query: Query<(&mut Health, &mut DamageAccumulator)>) {
+    for (mut health, mut accumulator) in query.iter_mut() {
+        if accumulator.pending > 0.0 {
+            let remaining = health.current as f32 - accumulator.pending;
+            health.current = if remaining < 0.0 { 0 } else { remaining as u16 };
+            accumulator.pending = 0.0;
+        }
+    }
+}
+
+const XP_PER_KILL: i32 = 25;
+
+fn death_system(
+    mut commands: Commands,
+    query: Query<(Entity, &Health, &DamageAccumulator)>,
+    mut pools_query: Query<&mut Pools>,
+) {
+    for (entity, h, accumulator) in query.iter() {
         if h.current == 0 {
+            if let Some(killer) = accumulator.last_attacker {
+                if let Ok(mut pools) = pools_query.get_mut(killer) {
+                    pools.xp += XP_PER_KILL;
+                }
+            }
+
             commands.entity(entity).despawn();
         }
     }
 }
 
+// Promotes entities across level thresholds, swapping their level-tier
+// marker component. A promotion out of `Novice` or `Veteran` is an archetype
+// move: the entity's component set changes mid-simulation instead of staying
+// fixed, which is exactly the structural churn this benchmark didn't
+// previously exercise. An `Elite` entity keeps gaining levels without any
+// marker swap, so those promotions are not counted as archetype moves.
+fn leveling_system(
+    mut commands: Commands,
+    mut query: Query<(
+        Entity,
+        &mut Pools,
+        &mut Health,
+        Option<&Novice>,
+        Option<&Veteran>,
+    )>,
+    mut moves: ResMut<ArchetypeMoveCounter>,
+) {
+    for (entity, mut pools, mut health, novice, veteran) in query.iter_mut() {
+        let threshold = pools.level as i32 * 100;
+        if pools.xp < threshold {
+            continue;
+        }
+
+        pools.level += 1;
+
+        // Growing a level widens both pools and tops them off, so the
+        // `hit_points`/`mana` maxima actually move instead of sitting at
+        // their spawn values for the rest of the run.
+        pools.hit_points.max += 10;
+        pools.hit_points.current = pools.hit_points.max;
+        pools.mana.max += 5;
+        pools.mana.current = pools.mana.max;
+        health.max = pools.hit_points.max as u16;
+        health.current = health.max;
+
+        let mut entity_commands = commands.entity(entity);
+
+        if novice.is_some() {
+            entity_commands.remove::<Novice>();
+            entity_commands.insert(Veteran);
+            moves.0 += 1;
+        } else if veteran.is_some() {
+            entity_commands.remove::<Veteran>();
+            entity_commands.insert(Elite);
+            moves.0 += 1;
+        }
+    }
+}
+
 fn status_effect_system(mut query: Query<(&Status, &mut Velocity)>) {
     for (status, mut v) in query.iter_mut() {
         if status.stunned == 1 {
@@ -244,9 +486,62 @@ fn velocity_damping_system(mut query: Query<&mut Velocity>) {
     }
 }
 
+// Rayon-backed counterparts of the three hottest queries. Each collects its
+// `Mut<T>` borrows into a `Vec` up front (so the chunking below can't trip
+// the borrow checker or bevy's archetype locks) and then fans the chunks out
+// across the global rayon pool, the same split-then-par_chunks_mut shape the
+// Entelect Monte Carlo strategy uses to fan simulations out.
+fn movement_system_parallel(
+    mut query: Query<(&mut Transform2D, &Velocity)>,
+    delta_time: Res<DeltaTime>,
+    parallel: Res<ParallelConfig>,
+) {
+    let dt = delta_time.0;
+    let mut items: Vec<(Mut<Transform2D>, &Velocity)> = query.iter_mut().collect();
+    items.par_chunks_mut(parallel.chunk_size).for_each(|chunk| {
+        for (t, v) in chunk.iter_mut() {
+            t.x += v.vx * dt;
+            t.y += v.vy * dt;
+        }
+    });
+}
+
+fn boundary_system_parallel(mut query: Query<&mut Transform2D>, parallel: Res<ParallelConfig>) {
+    let mut items: Vec<Mut<Transform2D>> = query.iter_mut().collect();
+    items.par_chunks_mut(parallel.chunk_size).for_each(|chunk| {
+        for t in chunk.iter_mut() {
+            if t.x < 0.0 {
+                t.x = 1000.0;
+            }
+            if t.x > 1000.0 {
+                t.x = 0.0;
+            }
+            if t.y < 0.0 {
+                t.y = 1000.0;
+            }
+            if t.y > 1000.0 {
+                t.y = 0.0;
+            }
+        }
+    });
+}
+
+fn velocity_damping_system_parallel(
+    mut query: Query<&mut Velocity>,
+    parallel: Res<ParallelConfig>,
+) {
+    let mut items: Vec<Mut<Velocity>> = query.iter_mut().collect();
+    items.par_chunks_mut(parallel.chunk_size).for_each(|chunk| {
+        for v in chunk.iter_mut() {
+            v.vx *= 0.99;
+            v.vy *= 0.99;
+        }
+    });
+}
+
 fn ai_behavior_system(mut query: Query<&mut Velocity>, frame: Res<FrameCounter>) {
     if frame.0 % 20 == 0 {
-        let mut rng = SimpleRng::new(frame.0);
+        let mut rng = XorShiftRng::new(frame.0);
         for mut v in query.iter_mut() {
             if rng.next_f32() > 0.9 {
                 v.vx += (rng.next_f32() - 0.5) * 2.0;
@@ -256,33 +551,279 @@ fn ai_behavior_system(mut query: Query<&mut Velocity>, frame: Res<FrameCounter>)
     }
 }
 
-fn run_benchmark(entity_count: usize) -> BenchmarkMetrics {
+// Simulates `depth` rounds of cooldown/armor-reduction/health-decay combat
+// math, returning total damage dealt as the rollout's reward. Only armor
+// reduces the damage here: unlike `combat_write_damage_system`, the
+// attacker's melee skill and the target's defense skill are not applied.
+fn simulate_rollout(
+    attacker_damage: u16,
+    target_health: u16,
+    target_armor: u16,
+    depth: u32,
+    rng: &mut XorShiftRng,
+) -> f32 {
+    let mut health = target_health as f32;
+    let mut cooldown = 0.0f32;
+    let max_cooldown = 1.0f32;
+    let mut total_damage = 0.0f32;
+
+    for _ in 0..depth {
+        if health <= 0.0 {
+            break;
+        }
+
+        let dt = 0.016 + (rng.next_f32() - 0.5) * 0.004;
+        cooldown = (cooldown - dt).max(0.0);
+
+        if cooldown == 0.0 {
+            let reduced = attacker_damage as f32 - target_armor as f32 * 0.1;
+            let damage_dealt = if reduced < 1.0 { 1.0 } else { reduced };
+            health = (health - damage_dealt).max(0.0);
+            total_damage += damage_dealt;
+            cooldown = max_cooldown;
+        }
+    }
+
+    total_damage
+}
+
+// UCB1: mean reward plus an exploration bonus that shrinks as a candidate
+// accrues visits relative to the total. A never-visited candidate has no
+// mean reward to report, so it scores as `f32::NEG_INFINITY` -- it loses the
+// argmax to any visited candidate rather than producing `0.0 / 0.0 = NaN`.
+fn ucb1_score(total_reward: f32, visits: u32, total_visits: f32) -> f32 {
+    if visits == 0 {
+        return f32::NEG_INFINITY;
+    }
+    let mean_reward = total_reward / visits as f32;
+    mean_reward + 2.0 * (total_visits.ln() / visits as f32).sqrt()
+}
+
+// Base cost, in mana, of a single simulated rollout before `Skills.magic`
+// discounts it. Keeps the search bounded by `Pools.mana` the same way a real
+// spell-casting AI would be.
+const MANA_COST_PER_ROLLOUT: f32 = 1.0;
+
+// Each point of `Skills.magic` stretches mana further, so a high-magic
+// attacker's search runs more rollouts per mana point than a low-magic one.
+fn mana_cost_per_rollout(magic: u8) -> f32 {
+    MANA_COST_PER_ROLLOUT / (1.0 + magic as f32 * 0.1)
+}
+
+// Caps how many enemies an attacker scouts per search. Without this cap
+// `enemies.len()` can run into the hundreds while the mana-bound budget
+// below tops out in the dozens, so the "visit everyone once" phase would
+// consume the whole budget and the UCB1-argmax phase would never run. A
+// small, fixed scouting radius is also what a real AI would use in place of
+// rolling out every enemy on the map.
+const MAX_MCTS_CANDIDATES: usize = 8;
+
+// Every 20 frames (gated like `ai_behavior_system`), each damage-dealing
+// entity re-picks its `Target` with a small Monte Carlo search: spend a
+// budget of `rollouts_per_candidate * enemies.len()` simulated skirmishes
+// (scouting at most `MAX_MCTS_CANDIDATES` enemies), capped by however much
+// mana the attacker has on hand, visiting every scouted enemy once and then
+// always rolling out whichever candidate currently has the highest UCB1
+// score, so visit counts genuinely diverge toward the promising candidates
+// instead of being spread evenly. Commit to the candidate with the highest
+// UCB1 score once the budget runs out.
+fn ai_mcts_system(
+    mut attacker_query: Query<(&Team, &Damage, &mut Target, &mut Pools, &Skills)>,
+    candidate_query: Query<(Entity, &Team, &Health, Option<&Armor>)>,
+    config: Res<MctsConfig>,
+    frame: Res<FrameCounter>,
+    mut rollout_counter: ResMut<RolloutCounter>,
+) {
+    if frame.0 % 20 == 0 {
+        let candidates: Vec<(Entity, u8, u16, u16)> = candidate_query
+            .iter()
+            .map(|(entity, team, health, armor)| {
+                (entity, team.id, health.current, armor.map_or(0, |a| a.value))
+            })
+            .collect();
+
+        let mut rng = XorShiftRng::new(frame.0 ^ 0x9E37_79B9);
+
+        for (team, dmg, mut target, mut pools, skills) in attacker_query.iter_mut() {
+            let enemies: Vec<&(Entity, u8, u16, u16)> = candidates
+                .iter()
+                .filter(|c| c.1 != team.id)
+                .take(MAX_MCTS_CANDIDATES)
+                .collect();
+            if enemies.is_empty() {
+                continue;
+            }
+
+            let mana_cost = mana_cost_per_rollout(skills.magic);
+            let affordable_rollouts = (pools.mana.current as f32 / mana_cost).floor().max(0.0) as u32;
+            let budget =
+                (enemies.len() as u32 * config.rollouts_per_candidate).min(affordable_rollouts);
+            if budget == 0 {
+                // No rollout budget configured (or no mana left): fall back
+                // to the lowest-health enemy rather than taking the argmax
+                // below, where every unvisited candidate scores
+                // `f32::NEG_INFINITY` and none is better than another.
+                let weakest = enemies.iter().enumerate().min_by_key(|(_, c)| c.2).unwrap().0;
+                target.entity_id = enemies[weakest].0;
+                continue;
+            }
+
+            pools.mana.current -= (budget as f32 * mana_cost).round() as i32;
+
+            let mut visits = vec![0u32; enemies.len()];
+            let mut rewards = vec![0.0f32; enemies.len()];
+
+            for _ in 0..budget {
+                // Visit every candidate once before trusting UCB1 scores
+                // (which need at least one sample to be defined), then let
+                // the current argmax score pick every remaining rollout.
+                let next = visits.iter().position(|&v| v == 0).unwrap_or_else(|| {
+                    let total_visits = visits.iter().sum::<u32>() as f32;
+                    (0..enemies.len())
+                        .max_by(|&a, &b| {
+                            ucb1_score(rewards[a], visits[a], total_visits)
+                                .partial_cmp(&ucb1_score(rewards[b], visits[b], total_visits))
+                                .unwrap()
+                        })
+                        .unwrap()
+                });
+
+                let reward = simulate_rollout(
+                    dmg.amount,
+                    enemies[next].2,
+                    enemies[next].3,
+                    config.rollout_depth,
+                    &mut rng,
+                );
+                visits[next] += 1;
+                rewards[next] += reward;
+            }
+
+            rollout_counter.0 += budget as u64;
+
+            let total_visits: f32 = visits.iter().sum::<u32>() as f32;
+            let best_idx = (0..enemies.len())
+                .max_by(|&a, &b| {
+                    ucb1_score(rewards[a], visits[a], total_visits)
+                        .partial_cmp(&ucb1_score(rewards[b], visits[b], total_visits))
+                        .unwrap()
+                })
+                .unwrap();
+
+            target.entity_id = enemies[best_idx].0;
+        }
+    }
+}
+
+// Builds and populates the app (resources, schedule, spawned entities) but
+// does not run any frames, so both `run_benchmark_inner` (which times
+// frames) and `run_determinism_check` (which only needs the final world
+// state) can share the exact same setup for a given seed.
+fn build_app(entity_count: usize, parallel: bool, legacy_combat: bool, seed: u32) -> App {
     let mut app = App::new();
 
     // Initialize resources
     app.insert_resource(FrameCounter(0));
     app.insert_resource(DeltaTime(0.016));
+    app.insert_resource(ParallelConfig::default());
+    app.insert_resource(MctsConfig::default());
+    app.insert_resource(RolloutCounter::default());
+    app.insert_resource(ArchetypeMoveCounter::default());
 
-    // Add all systems
-    app.add_systems(
-        Update,
-        (
-            movement_system,
-            rotation_system,
-            boundary_system,
-            health_regen_system,
-            cooldown_system,
-            combat_system,
-            death_system,
-            status_effect_system,
-            lifetime_system,
-            velocity_damping_system,
-            ai_behavior_system,
-        ).chain(),
-    );
+    // The 14 systems split into two component-disjoint chains -- a
+    // Transform2D/Velocity "movement" chain and a Health/Cooldown/Pools
+    // "combat" chain -- plus `lifetime_system`, which touches neither and
+    // floats free. Every system within a chain keeps its serial relative
+    // order (movement must still precede boundary, health_regen must still
+    // precede apply_damage, etc.), but nothing here orders the two chains or
+    // `lifetime_system` against each other, so bevy's own multithreaded
+    // executor is free to run them concurrently wherever the data access
+    // genuinely doesn't overlap. Both modes register identical chains, so
+    // the two schedules simulate the identical game frame-for-frame and the
+    // benchmark's Avg/P99/Speedup columns compare like workloads -- the same
+    // reason `run_determinism_check` holds for both modes. Parallel
+    // mode's extra speedup on top of that concurrency comes from swapping
+    // the three hot queries for their rayon-chunked counterparts, which fan
+    // each one out across entity chunks internally without changing
+    // system-to-system ordering.
+    if parallel {
+        app.add_systems(
+            Update,
+            (
+                movement_system_parallel,
+                rotation_system,
+                boundary_system_parallel,
+                status_effect_system,
+                velocity_damping_system_parallel,
+                ai_behavior_system,
+            ).chain(),
+        );
+        app.add_systems(
+            Update,
+            (
+                health_regen_system,
+                cooldown_system,
+                ai_mcts_system,
+                combat_write_damage_system,
+                apply_damage_system,
+                death_system,
+                leveling_system,
+            ).chain(),
+        );
+        app.add_systems(Update, lifetime_system);
+    } else if legacy_combat {
+        app.add_systems(
+            Update,
+            (
+                movement_system,
+                rotation_system,
+                boundary_system,
+                status_effect_system,
+                velocity_damping_system,
+                ai_behavior_system,
+            ).chain(),
+        );
+        app.add_systems(
+            Update,
+            (
+                health_regen_system,
+                cooldown_system,
+                ai_mcts_system,
+                combat_system_legacy,
+                death_system,
+                leveling_system,
+            ).chain(),
+        );
+        app.add_systems(Update, lifetime_system);
+    } else {
+        app.add_systems(
+            Update,
+            (
+                movement_system,
+                rotation_system,
+                boundary_system,
+                status_effect_system,
+                velocity_damping_system,
+                ai_behavior_system,
+            ).chain(),
+        );
+        app.add_systems(
+            Update,
+            (
+                health_regen_system,
+                cooldown_system,
+                ai_mcts_system,
+                combat_write_damage_system,
+                apply_damage_system,
+                death_system,
+                leveling_system,
+            ).chain(),
+        );
+        app.add_systems(Update, lifetime_system);
+    }
 
     // Setup entities
-    let mut rng = SimpleRng::new(12345);
+    let mut rng = XorShiftRng::new(seed);
     let mut world = app.world_mut();
 
     for _i in 0..entity_count {
@@ -300,6 +841,19 @@ fn run_benchmark(entity_count: usize) -> BenchmarkMetrics {
                 current: 100,
                 max: 100,
             },
+            DamageAccumulator::default(),
+            Pools {
+                hit_points: Pool { current: 100, max: 100 },
+                mana: Pool { current: 50, max: 50 },
+                xp: 0,
+                level: 1,
+            },
+            Skills {
+                melee: rng.next_u8() % 10,
+                defense: rng.next_u8() % 10,
+                magic: rng.next_u8() % 10,
+            },
+            Novice,
         )).id();
 
         // 80% have armor
@@ -347,6 +901,85 @@ fn run_benchmark(entity_count: usize) -> BenchmarkMetrics {
         }
     }
 
+    app
+}
+
+// Hashes the parts of the final world state that entity spawning, targeting
+// and `ai_behavior_system` randomness can perturb. Run twice with the same
+// seed, the two hashes must match, or a scheduling/iteration-order change
+// has introduced nondeterminism.
+fn hash_world_state(app: &mut App) -> u64 {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = DefaultHasher::new();
+    let world = app.world_mut();
+
+    let mut transforms: Vec<(f32, f32, f32)> = world
+        .query::<&Transform2D>()
+        .iter(world)
+        .map(|t| (t.x, t.y, t.rotation))
+        .collect();
+    transforms.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    for (x, y, rotation) in transforms {
+        x.to_bits().hash(&mut hasher);
+        y.to_bits().hash(&mut hasher);
+        rotation.to_bits().hash(&mut hasher);
+    }
+
+    let mut healths: Vec<u16> = world.query::<&Health>().iter(world).map(|h| h.current).collect();
+    healths.sort_unstable();
+    healths.hash(&mut hasher);
+
+    let mut velocities: Vec<(f32, f32)> = world
+        .query::<&Velocity>()
+        .iter(world)
+        .map(|v| (v.vx, v.vy))
+        .collect();
+    velocities.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    for (vx, vy) in velocities {
+        vx.to_bits().hash(&mut hasher);
+        vy.to_bits().hash(&mut hasher);
+    }
+
+    hasher.finish()
+}
+
+// Runs the same seed through the benchmark twice and returns whether the
+// resulting world-state hashes match (`main` asserts on the result),
+// surfacing any nondeterminism that parallel scheduling or iteration-order
+// changes might introduce.
+fn run_determinism_check(entity_count: usize, parallel: bool) -> bool {
+    let seed = 424_242;
+
+    let mut first = build_app(entity_count, parallel, false, seed);
+    for frame in 0..60u32 {
+        first.world_mut().resource_mut::<FrameCounter>().0 = frame;
+        first.update();
+    }
+    let first_hash = hash_world_state(&mut first);
+
+    let mut second = build_app(entity_count, parallel, false, seed);
+    for frame in 0..60u32 {
+        second.world_mut().resource_mut::<FrameCounter>().0 = frame;
+        second.update();
+    }
+    let second_hash = hash_world_state(&mut second);
+
+    first_hash == second_hash
+}
+
+fn run_benchmark(entity_count: usize, parallel: bool) -> BenchmarkMetrics {
+    run_benchmark_inner(entity_count, parallel, false)
+}
+
+// `legacy_combat` swaps the accumulator-based combat phases for
+// `combat_system_legacy`, the original collect-into-a-`Vec`-and-reapply
+// system. It only exists so `run_combat_comparison` can report how much the
+// accumulator redesign actually saves; the regular benchmark never sets it.
+fn run_benchmark_inner(entity_count: usize, parallel: bool, legacy_combat: bool) -> BenchmarkMetrics {
+    let mut app = build_app(entity_count, parallel, legacy_combat, 12345);
+
     // Run simulation for 60 frames
     let frame_count = 60;
     let mut frame_times = Vec::with_capacity(frame_count);
@@ -397,6 +1030,15 @@ fn run_benchmark(entity_count: usize) -> BenchmarkMetrics {
         }
     }
 
+    let total_rollouts = app.world().resource::<RolloutCounter>().0;
+    let total_seconds: f32 = frame_times.iter().sum::<f32>() / 1000.0;
+    let rollouts_per_sec = if total_seconds > 0.0 {
+        total_rollouts as f32 / total_seconds
+    } else {
+        0.0
+    };
+    let archetype_moves = app.world().resource::<ArchetypeMoveCounter>().0;
+
     BenchmarkMetrics {
         avg,
         min,
@@ -408,50 +1050,134 @@ fn run_benchmark(entity_count: usize) -> BenchmarkMetrics {
         percent60,
         percent30,
         jank_score,
+        rollouts_per_sec,
+        archetype_moves,
+    }
+}
+
+fn average_runs(runs: &[BenchmarkMetrics]) -> BenchmarkMetrics {
+    let len = runs.len() as f32;
+    BenchmarkMetrics {
+        avg: runs.iter().map(|r| r.avg).sum::<f32>() / len,
+        min: runs.iter().map(|r| r.min).fold(f32::INFINITY, f32::min),
+        max: runs.iter().map(|r| r.max).fold(f32::NEG_INFINITY, f32::max),
+        p50: runs.iter().map(|r| r.p50).sum::<f32>() / len,
+        p95: runs.iter().map(|r| r.p95).sum::<f32>() / len,
+        p99: runs.iter().map(|r| r.p99).sum::<f32>() / len,
+        std_dev: runs.iter().map(|r| r.std_dev).sum::<f32>() / len,
+        percent60: runs.iter().map(|r| r.percent60).sum::<f32>() / len,
+        percent30: runs.iter().map(|r| r.percent30).sum::<f32>() / len,
+        jank_score: runs.iter().map(|r| r.jank_score).sum::<u32>() / runs.len() as u32,
+        rollouts_per_sec: runs.iter().map(|r| r.rollouts_per_sec).sum::<f32>() / len,
+        archetype_moves: runs.iter().map(|r| r.archetype_moves).sum::<u32>() / runs.len() as u32,
+    }
+}
+
+// Compares the accumulator-based combat path against the legacy
+// collect-into-a-Vec one it replaced, reporting the avg/p99 delta per entity
+// count. Serial-only: the comparison is about the combat system itself, not
+// the parallel scheduling from `run_benchmark`.
+fn run_combat_comparison(entity_counts: &[usize]) {
+    println!("\nCombat system: accumulator vs legacy Vec collection (serial)\n");
+    println!("| Entities | Legacy Avg | Accum Avg | Avg Delta | Legacy P99 | Accum P99 | P99 Delta |");
+    println!("|----------|------------|-----------|-----------|------------|-----------|-----------|");
+
+    for &count in entity_counts {
+        let legacy_runs: Vec<_> = (0..5)
+            .map(|_| run_benchmark_inner(count, false, true))
+            .collect();
+        let accum_runs: Vec<_> = (0..5)
+            .map(|_| run_benchmark_inner(count, false, false))
+            .collect();
+
+        let legacy = average_runs(&legacy_runs);
+        let accum = average_runs(&accum_runs);
+
+        println!(
+            "| {:>8} | {:>8.2}ms | {:>7.2}ms | {:>+7.2}ms | {:>8.2}ms | {:>7.2}ms | {:>+7.2}ms |",
+            count,
+            legacy.avg,
+            accum.avg,
+            accum.avg - legacy.avg,
+            legacy.p99,
+            accum.p99,
+            accum.p99 - legacy.p99,
+        );
     }
 }
 
 fn main() {
-    println!("Bevy ECS Benchmark - Complex Game Simulation (11 Systems)\n");
-    println!("Running 5 iterations per entity count for averaging...\n");
+    println!("Bevy ECS Benchmark - Complex Game Simulation (14 Systems)\n");
+
+    println!("Determinism check (same seed, 60 frames, twice)...");
+    let serial_deterministic = run_determinism_check(1_000, false);
+    let parallel_deterministic = run_determinism_check(1_000, true);
+    println!(
+        "  serial:   {}",
+        if serial_deterministic { "PASS" } else { "FAIL (hashes differ across identical runs)" }
+    );
+    println!(
+        "  parallel: {}",
+        if parallel_deterministic { "PASS" } else { "FAIL (hashes differ across identical runs)" }
+    );
+    assert!(serial_deterministic, "serial mode produced nondeterministic world state for the same seed");
+    assert!(parallel_deterministic, "parallel mode produced nondeterministic world state for the same seed");
+    println!();
+
+    println!("Running 5 iterations per entity count, per mode, for averaging...\n");
 
     let entity_counts = [500, 1_000, 5_000, 10_000, 15_000, 25_000, 50_000, 100_000];
 
-    println!("| Entities | Avg   | P50   | P95   | P99   | Max   | StdDev | @60fps | @30fps | Jank |");
-    println!("|----------|-------|-------|-------|-------|-------|--------|--------|--------|------|");
+    println!("| Entities | Mode     | Avg   | P50   | P95   | P99   | Max   | StdDev | @60fps | @30fps | Jank | Rollouts/s | ArcMoves | Speedup |");
+    println!("|----------|----------|-------|-------|-------|-------|-------|--------|--------|--------|------|------------|----------|---------|");
 
     for count in entity_counts {
-        // Run 5 times and collect all metrics
-        let mut runs = Vec::new();
+        let mut serial_runs = Vec::new();
+        let mut parallel_runs = Vec::new();
 
         for run in 0..5 {
-            eprintln!("  Run {}/{} for {} entities...", run + 1, 5, count);
-            runs.push(run_benchmark(count));
+            eprintln!("  Run {}/{} (serial) for {} entities...", run + 1, 5, count);
+            serial_runs.push(run_benchmark(count, false));
+            eprintln!("  Run {}/{} (parallel) for {} entities...", run + 1, 5, count);
+            parallel_runs.push(run_benchmark(count, true));
         }
 
-        // Average all metrics across runs
-        let avg_avg = runs.iter().map(|r| r.avg).sum::<f32>() / runs.len() as f32;
-        let avg_p50 = runs.iter().map(|r| r.p50).sum::<f32>() / runs.len() as f32;
-        let avg_p95 = runs.iter().map(|r| r.p95).sum::<f32>() / runs.len() as f32;
-        let avg_p99 = runs.iter().map(|r| r.p99).sum::<f32>() / runs.len() as f32;
-        let max_max = runs.iter().map(|r| r.max).fold(f32::NEG_INFINITY, f32::max);
-        let avg_std_dev = runs.iter().map(|r| r.std_dev).sum::<f32>() / runs.len() as f32;
-        let avg_percent60 = runs.iter().map(|r| r.percent60).sum::<f32>() / runs.len() as f32;
-        let avg_percent30 = runs.iter().map(|r| r.percent30).sum::<f32>() / runs.len() as f32;
-        let avg_jank = runs.iter().map(|r| r.jank_score).sum::<u32>() / runs.len() as u32;
+        let serial = average_runs(&serial_runs);
+        let parallel = average_runs(&parallel_runs);
+        let speedup = serial.avg / parallel.avg;
 
         println!(
-            "| {:>8} | {:>5.2}ms | {:>5.2}ms | {:>5.2}ms | {:>5.2}ms | {:>5.2}ms | {:>6.2}ms | {:>5.0}% | {:>5.0}% | {:>4} |",
+            "| {:>8} | serial   | {:>5.2}ms | {:>5.2}ms | {:>5.2}ms | {:>5.2}ms | {:>5.2}ms | {:>6.2}ms | {:>5.0}% | {:>5.0}% | {:>4} | {:>10.0} | {:>8} |    -    |",
             count,
-            avg_avg,
-            avg_p50,
-            avg_p95,
-            avg_p99,
-            max_max,
-            avg_std_dev,
-            avg_percent60,
-            avg_percent30,
-            avg_jank
+            serial.avg,
+            serial.p50,
+            serial.p95,
+            serial.p99,
+            serial.max,
+            serial.std_dev,
+            serial.percent60,
+            serial.percent30,
+            serial.jank_score,
+            serial.rollouts_per_sec,
+            serial.archetype_moves,
+        );
+        println!(
+            "| {:>8} | parallel | {:>5.2}ms | {:>5.2}ms | {:>5.2}ms | {:>5.2}ms | {:>5.2}ms | {:>6.2}ms | {:>5.0}% | {:>5.0}% | {:>4} | {:>10.0} | {:>8} | {:>6.2}x |",
+            count,
+            parallel.avg,
+            parallel.p50,
+            parallel.p95,
+            parallel.p99,
+            parallel.max,
+            parallel.std_dev,
+            parallel.percent60,
+            parallel.percent30,
+            parallel.jank_score,
+            parallel.rollouts_per_sec,
+            parallel.archetype_moves,
+            speedup,
         );
     }
+
+    run_combat_comparison(&entity_counts);
 }